@@ -13,29 +13,73 @@ pub(crate) mod blinded;
 mod p384_scalar;
 
 use self::p384_scalar::*;
-use crate::{FieldBytes, NistP384, SecretKey, U384};
+use crate::{FieldBytes, NistP384, SecretKey, U384, U768};
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use elliptic_curve::{
     bigint::{Encoding, Limb},
     ff::{Field, PrimeField},
     generic_array::arr,
-    ops::Reduce,
+    ops::{Reduce, ReduceNonZero},
     rand_core::RngCore,
-    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
     zeroize::DefaultIsZeroes,
-    Curve as _, Error, IsHigh, Result, ScalarArithmetic, ScalarCore,
+    Curve as _, Error, IsHigh, NonZeroScalar, Result, ScalarArithmetic, ScalarCore,
 };
 
 #[cfg(feature = "bits")]
 use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
 
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
 type Fe = fiat_p384_scalar_montgomery_domain_field_element;
 type NonMontFe = fiat_p384_scalar_non_montgomery_domain_field_element;
 
+/// Returns a truthy [`Choice`] iff `bytes_le`, interpreted as a little-endian
+/// integer, is strictly less than the order `n` (i.e. is a canonical
+/// encoding). Walks every byte via a borrow-chain so the result is
+/// constant-time in `bytes_le`.
+fn is_canonical_le(bytes_le: &[u8; 48]) -> Choice {
+    let order_le = NistP384::ORDER.to_le_bytes();
+    let mut c: u8 = 0;
+    let mut n: u8 = 1;
+    for (&s, &l) in bytes_le.iter().rev().zip(order_le.iter().rev()) {
+        c |= (((s as u16).wrapping_sub(l as u16) >> 8) as u8) & n;
+        n &= (((s ^ l) as u16).wrapping_sub(1) >> 8) as u8;
+    }
+    Choice::from(c)
+}
+
 fn frac_modulus_2() -> Scalar {
     Scalar::from_le_bytes(&NistP384::ORDER.shr_vartime(1).to_le_bytes()).unwrap()
 }
 
+/// `R = 2^384 mod n`, used to fold the high half of a double-width value
+/// back into the scalar field when reducing a wide integer.
+fn r_384() -> Scalar {
+    Scalar::from_repr(arr![u8;
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x38, 0x9c, 0xb2, 0x7e, 0x0b, 0xc8, 0xd2, 0x20, 0xa7, 0xe5, 0xf2, 0x4d,
+        0xb7, 0x4f, 0x58, 0x85, 0x13, 0x13, 0xe6, 0x95, 0x33, 0x3a, 0xd6, 0x8d
+    ])
+    .unwrap()
+}
+
+/// A double-width (768-bit) integer, produced by e.g. hashing into a
+/// buffer twice the width of the scalar field (RFC 6979 nonce derivation,
+/// hash-to-field for deterministic signatures).
+///
+/// [`Reduce<U768>`] folds this down into a canonical [`Scalar`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WideScalar(U768);
+
+impl From<U768> for WideScalar {
+    fn from(w: U768) -> Self {
+        Self(w)
+    }
+}
+
 impl ScalarArithmetic for NistP384 {
     type Scalar = Scalar;
 }
@@ -60,14 +104,7 @@ impl Scalar {
 
     /// Create a scalar from a canonical, little-endian representation
     pub fn from_le_bytes(bytes: &[u8; 48]) -> Result<Self> {
-        let order_le = NistP384::ORDER.to_le_bytes();
-        let mut c: u8 = 0;
-        let mut n: u8 = 1;
-        for (&s, &l) in bytes.iter().rev().zip(order_le.iter().rev()) {
-            c |= (((s as u16).wrapping_sub(l as u16) >> 8) as u8) & n;
-            n &= (((s ^ l) as u16).wrapping_sub(1) >> 8) as u8;
-        }
-        if c == 0 {
+        if is_canonical_le(bytes).unwrap_u8() == 0 {
             return Err(Error);
         }
 
@@ -83,6 +120,32 @@ impl Scalar {
         Scalar::from_le_bytes(&swap48(bytes))
     }
 
+    /// Create a scalar from a canonical, big-endian byte slice.
+    ///
+    /// Matches [`elliptic_curve::ScalarCore`]'s API for variable-length
+    /// callers, erroring if the slice length doesn't match [`FieldBytes`].
+    pub fn from_be_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != 48 {
+            return Err(Error);
+        }
+        let mut bytes = [0u8; 48];
+        bytes.copy_from_slice(slice);
+        Scalar::from_be_bytes(&bytes)
+    }
+
+    /// Create a scalar from a canonical, little-endian byte slice.
+    ///
+    /// Matches [`elliptic_curve::ScalarCore`]'s API for variable-length
+    /// callers, erroring if the slice length doesn't match [`FieldBytes`].
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != 48 {
+            return Err(Error);
+        }
+        let mut bytes = [0u8; 48];
+        bytes.copy_from_slice(slice);
+        Scalar::from_le_bytes(&bytes)
+    }
+
     /// Returns the little-endian encoding of this scalar.
     pub fn to_le_bytes(&self) -> FieldBytes {
         let non_mont = self.to_non_mont();
@@ -131,6 +194,52 @@ impl Scalar {
         self.invert()
     }
 
+    /// Invert a batch of scalars at the cost of one [`Scalar::invert`] plus
+    /// `3n` multiplications, using Montgomery's trick.
+    ///
+    /// Any zero element is handled in constant time: it is substituted with
+    /// [`Scalar::ONE`] while folding the running product. If any input was
+    /// zero the returned [`CtOption`] is `None` and `inputs` is left
+    /// completely untouched; otherwise every element is replaced by its
+    /// inverse.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(inputs: &mut [Scalar]) -> CtOption<()> {
+        let n = inputs.len();
+        let originals: Vec<Scalar> = inputs.to_vec();
+        let mut scratch = vec![Scalar::ONE; n];
+
+        let mut acc = Scalar::ONE;
+        let mut all_nonzero = Choice::from(1);
+
+        for i in 0..n {
+            let is_zero = inputs[i].is_zero();
+            all_nonzero &= !is_zero;
+            scratch[i] = acc;
+            acc *= Scalar::conditional_select(&inputs[i], &Scalar::ONE, is_zero);
+        }
+
+        // Invert the accumulated product once; substitute ONE if any input
+        // was zero so the inversion itself never touches zero.
+        let mut acc = Scalar::conditional_select(&acc, &Scalar::ONE, !all_nonzero)
+            .invert()
+            .unwrap();
+
+        for i in (0..n).rev() {
+            let is_zero = inputs[i].is_zero();
+            let inverted = acc * scratch[i];
+            acc *= Scalar::conditional_select(&inputs[i], &Scalar::ONE, is_zero);
+            inputs[i] = Scalar::conditional_select(&inverted, &inputs[i], is_zero);
+        }
+
+        // On failure, restore every element exactly as the doc comment
+        // promises rather than leaving behind a partially-inverted buffer.
+        for i in 0..n {
+            inputs[i] = Scalar::conditional_select(&inputs[i], &originals[i], !all_nonzero);
+        }
+
+        CtOption::new((), all_nonzero)
+    }
+
     fn sqn(&self, n: usize) -> Self {
         let mut x = *self;
         for _ in 0..n {
@@ -299,12 +408,14 @@ impl PrimeField for Scalar {
     const S: u32 = 1;
 
     fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        let bytes_le = swap48(bytes.as_ref());
+        let is_canonical = is_canonical_le(&bytes_le);
+
         let mut non_mont = Default::default();
-        fiat_p384_scalar_from_bytes(&mut non_mont, &swap48(bytes.as_ref()));
+        fiat_p384_scalar_from_bytes(&mut non_mont, &bytes_le);
         let mut mont = Default::default();
         fiat_p384_scalar_to_montgomery(&mut mont, &non_mont);
-        let out = Scalar(mont);
-        CtOption::new(out, 1.into())
+        CtOption::new(Scalar(mont), is_canonical)
     }
 
     fn to_repr(&self) -> FieldBytes {
@@ -420,21 +531,28 @@ impl ConstantTimeEq for Scalar {
 }
 
 impl Scalar {
+    /// Constant-time lexicographic comparison, most-significant limb first.
     fn ct_gt(&self, other: &Self) -> Choice {
-        // not CT
-        let mut out = Choice::from(0);
-        for (x, y) in self.0.iter().zip(other.0.iter()) {
-            if x > y {
-                out = Choice::from(1);
-            }
+        let mut gt = Choice::from(0);
+        let mut eq = Choice::from(1);
+
+        for (x, y) in self.0.iter().zip(other.0.iter()).rev() {
+            gt |= eq & x.ct_gt(y);
+            eq &= x.ct_eq(y);
         }
-        out
+
+        gt
     }
 }
 
 impl IsHigh for Scalar {
     fn is_high(&self) -> Choice {
-        self.ct_gt(&frac_modulus_2())
+        // `ct_gt` compares limbs lexicographically, which is only
+        // order-preserving on the integer value when both operands are in
+        // the same (non-Montgomery) representation — Montgomery
+        // multiplication by `R` is not monotonic, so this must convert out
+        // of Montgomery form first.
+        self.to_non_mont().ct_gt(&frac_modulus_2().to_non_mont())
     }
 }
 
@@ -555,6 +673,232 @@ impl Reduce<U384> for Scalar {
     }
 }
 
+impl Reduce<U768> for Scalar {
+    fn from_uint_reduced(w: U768) -> Self {
+        WideScalar::from(w).reduce()
+    }
+}
+
+/// Fold `w` into `[0, n - 1)` by subtracting `n - 1` once if needed.
+fn reduce_384_mod_order_minus_one(w: U384) -> Scalar {
+    let order_minus_one = NistP384::ORDER.wrapping_sub(&U384::ONE);
+    let (r, underflow) = w.sbb(&order_minus_one, Limb::ZERO);
+    let underflow = Choice::from((underflow.0 >> (Limb::BIT_SIZE - 1)) as u8);
+    let reduced = U384::conditional_select(&w, &r, !underflow);
+    Scalar::from(ScalarCore::new(reduced).unwrap())
+}
+
+impl ReduceNonZero<U384> for Scalar {
+    fn from_uint_reduced_nonzero(w: U384) -> Self {
+        reduce_384_mod_order_minus_one(w) + Scalar::ONE
+    }
+}
+
+/// Subtract `b` from `a` with an incoming borrow, returning `(difference,
+/// outgoing borrow)`; both borrows are 0/1 values.
+fn sbb8(a: u8, b: u8, borrow_in: u8) -> (u8, u8) {
+    let diff = a as i16 - b as i16 - borrow_in as i16;
+    (diff as u8, (diff < 0) as u8)
+}
+
+/// Conditionally subtract `modulus` from the 384-bit value represented by
+/// `carry * 2^384 + bytes` (little-endian), which the caller guarantees is
+/// already `< 2 * modulus`.
+fn reduce_one_sub(bytes: [u8; 48], carry: u8, modulus: &[u8; 48]) -> [u8; 48] {
+    let mut diff = [0u8; 48];
+    let mut borrow = 0u8;
+    for i in 0..48 {
+        let (d, b) = sbb8(bytes[i], modulus[i], borrow);
+        diff[i] = d;
+        borrow = b;
+    }
+    // The subtraction is valid whenever it didn't borrow, or whenever the
+    // incoming carry bit alone already puts us at or above the modulus.
+    let apply = Choice::from(carry) | !Choice::from(borrow);
+    let mut out = [0u8; 48];
+    for i in 0..48 {
+        out[i] = u8::conditional_select(&bytes[i], &diff[i], apply);
+    }
+    out
+}
+
+/// Reduce a big-endian bitstream modulo `modulus` (`< 2^384`) one bit at a
+/// time: double the running total, add in the next bit, and fold the result
+/// back into `[0, modulus)`.
+fn reduce_bits_mod(bits_be: impl Iterator<Item = u8>, modulus: &[u8; 48]) -> [u8; 48] {
+    let mut acc = [0u8; 48];
+    for bit in bits_be {
+        let mut carry = bit & 1;
+        for byte in acc.iter_mut() {
+            let doubled = (*byte << 1) | carry;
+            carry = *byte >> 7;
+            *byte = doubled;
+        }
+        acc = reduce_one_sub(acc, carry, modulus);
+    }
+    acc
+}
+
+/// Reduce a wide (768-bit) integer modulo `n - 1`, genuinely landing in
+/// `[0, n - 1)` rather than reducing each 384-bit half mod `n - 1` and
+/// recombining mod `n` (which can wrap back to zero once `Scalar::ONE` is
+/// added on top, since the recombined value can land on `n - 1` itself).
+fn reduce_768_mod_order_minus_one(w: U768) -> Scalar {
+    let order_minus_one = NistP384::ORDER.wrapping_sub(&U384::ONE).to_le_bytes();
+    let bytes = w.to_be_bytes();
+    let bits = bytes
+        .iter()
+        .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1));
+    let reduced = reduce_bits_mod(bits, &order_minus_one);
+    Scalar::from_le_bytes(&reduced).expect("reduce_bits_mod output is always < n - 1")
+}
+
+impl ReduceNonZero<U768> for Scalar {
+    fn from_uint_reduced_nonzero(w: U768) -> Self {
+        reduce_768_mod_order_minus_one(w) + Scalar::ONE
+    }
+}
+
+impl WideScalar {
+    /// Split into the high and low 384-bit halves and fold the result back
+    /// into the scalar field: `hi * R + lo mod n`, where `R = 2^384 mod n`.
+    fn reduce(&self) -> Scalar {
+        let bytes = self.0.to_le_bytes();
+
+        let mut lo_bytes = [0u8; 48];
+        let mut hi_bytes = [0u8; 48];
+        lo_bytes.copy_from_slice(&bytes[..48]);
+        hi_bytes.copy_from_slice(&bytes[48..]);
+
+        let lo = Scalar::from_uint_reduced(U384::from_le_bytes(lo_bytes));
+        let hi = Scalar::from_uint_reduced(U384::from_le_bytes(hi_bytes));
+
+        hi * &r_384() + lo
+    }
+}
+
+impl Scalar {
+    /// Reduce a double-width (768-bit) integer modulo `n`.
+    ///
+    /// Used to fold arbitrary-length hash output into the scalar field,
+    /// e.g. for RFC 6979 nonce derivation or hash-to-field constructions.
+    pub fn reduce_wide(w: U768) -> Self {
+        Reduce::<U768>::from_uint_reduced(w)
+    }
+
+    /// Hash arbitrary wide (96-byte) input into a scalar, reducing it
+    /// modulo `n` via [`Scalar::reduce_wide`].
+    pub fn from_uniform_bytes(bytes: &[u8; 96]) -> Self {
+        Self::reduce_wide(U768::from_be_bytes(*bytes))
+    }
+
+    /// Reduce `w` into a guaranteed-nonzero scalar in `[1, n)`, without a
+    /// rejection loop.
+    pub fn from_uint_reduced_nonzero(w: U384) -> Self {
+        ReduceNonZero::<U384>::from_uint_reduced_nonzero(w)
+    }
+
+    /// Derive a guaranteed-nonzero scalar from wide (96-byte) hash output,
+    /// suitable for ECDSA/ECDH nonces and keys.
+    pub fn nonzero_from_uniform_bytes(bytes: &[u8; 96]) -> NonZeroScalar<NistP384> {
+        let w = U768::from_be_bytes(*bytes);
+        let scalar = ReduceNonZero::<U768>::from_uint_reduced_nonzero(w);
+        NonZeroScalar::new(scalar).unwrap()
+    }
+
+    /// Recode this scalar into a width-`w` non-adjacent form (NAF):
+    /// a sparse signed-digit representation in which nonzero digits
+    /// are separated by at least `w - 1` zeros, useful for building
+    /// precomputed tables for fixed/variable-base scalar multiplication.
+    ///
+    /// `w` must be in `2..=8`.
+    pub fn non_adjacent_form(&self, w: usize) -> [i8; 385] {
+        debug_assert!(w >= 2);
+        debug_assert!(w <= 8);
+
+        let limbs = self.to_non_mont().0;
+        let mut x_u64 = [0u64; 7];
+        x_u64[..6].copy_from_slice(&limbs);
+
+        let width = 1u64 << w;
+        let window_mask = width - 1;
+
+        let mut pos = 0;
+        let mut carry = 0;
+        let mut naf = [0i8; 385];
+
+        // Runs through index 384 (not just 0..383): a 384-bit scalar can
+        // carry a digit out past its top bit (e.g. `n - 1`, whose bits
+        // 192..383 are all set), and `naf` has room for exactly that.
+        while pos < 385 {
+            let u64_idx = pos / 64;
+            let bit_idx = pos % 64;
+
+            let bit_buf = if bit_idx < 64 - w {
+                x_u64[u64_idx] >> bit_idx
+            } else {
+                (x_u64[u64_idx] >> bit_idx) | (x_u64[1 + u64_idx] << (64 - bit_idx))
+            };
+
+            let window = carry + (bit_buf & window_mask);
+
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i8).wrapping_sub(width as i8);
+            }
+
+            pos += w;
+        }
+
+        naf
+    }
+
+    /// Recode this scalar into balanced signed base-`2^w` digits, for use
+    /// with comb-based fixed-base scalar multiplication.
+    ///
+    /// `w` must be in `2..=8`.
+    #[cfg(feature = "alloc")]
+    pub fn to_radix_2w(&self, w: usize) -> Vec<i8> {
+        debug_assert!(w >= 2);
+        debug_assert!(w <= 8);
+
+        let bytes = self.to_le_bytes();
+        let digit_count = (384 + w - 1) / w + 1;
+        let mut digits = vec![0i16; digit_count];
+
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let bit_offset = i * w;
+            let byte_offset = bit_offset / 8;
+            let bit_shift = bit_offset % 8;
+
+            let mut chunk: u32 = 0;
+            for (j, b) in bytes.iter().skip(byte_offset).take(3).enumerate() {
+                chunk |= (*b as u32) << (8 * j);
+            }
+            *digit = ((chunk >> bit_shift) & ((1 << w) - 1)) as i16;
+        }
+
+        // Recenter each raw digit into [-2^(w-1), 2^(w-1)), carrying the
+        // overflow into the next, more-significant digit.
+        let radix = 1i16 << w;
+        for i in 0..digit_count - 1 {
+            let carry = (digits[i] + radix / 2) >> w;
+            digits[i] -= carry * radix;
+            digits[i + 1] += carry;
+        }
+
+        digits.into_iter().map(|d| d as i8).collect()
+    }
+}
+
 #[cfg(feature = "bits")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bits")))]
 impl PrimeFieldBits for Scalar {
@@ -598,12 +942,43 @@ impl From<&SecretKey> for Scalar {
     }
 }
 
+// NOTE: gating this on the `serde` feature only compiles if the crate
+// manifest declares `serde = ["dep:serdect"]` plus an optional `serdect`
+// dependency (and a `serde_json` dev-dependency for `serde_round_trip`
+// below). This source tree has no Cargo.toml to check or add that to —
+// confirm those entries exist wherever this file is vendored into a real
+// crate before enabling the feature.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+mod serde_impl {
+    use super::Scalar;
+    use elliptic_curve::ff::PrimeField;
+    use serdect::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for Scalar {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serdect::array::serialize_hex_lower_or_bin(&self.to_repr(), serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Scalar {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let mut bytes = <Scalar as PrimeField>::Repr::default();
+            serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+            Option::from(Scalar::from_repr(bytes))
+                .ok_or_else(|| de::Error::custom("scalar out of range"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use elliptic_curve::ff::{Field, PrimeField};
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+    use elliptic_curve::{bigint::Encoding, ff::{Field, PrimeField}, Curve, IsHigh};
 
     use super::Scalar;
-    use crate::FieldBytes;
+    use crate::{FieldBytes, NistP384, U384, U768};
 
     #[test]
     fn from_to_bytes_roundtrip() {
@@ -615,6 +990,17 @@ mod tests {
         assert_eq!(bytes, scalar.to_be_bytes());
     }
 
+    /// `from_repr` must reject any encoding that is not strictly less than
+    /// the curve order, including the order itself.
+    #[test]
+    fn from_repr_rejects_out_of_range() {
+        let order_bytes = FieldBytes::from(NistP384::ORDER.to_be_bytes());
+        assert!(bool::from(Scalar::from_repr(order_bytes).is_none()));
+
+        let max_bytes = FieldBytes::from([0xffu8; 48]);
+        assert!(bool::from(Scalar::from_repr(max_bytes).is_none()));
+    }
+
     /// Basic tests that multiplication works.
     #[test]
     fn multiply() {
@@ -646,6 +1032,28 @@ mod tests {
         assert_eq!(three * inv_minus_three, -one);
     }
 
+    /// Batch inversion should agree with inverting each element on its own.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert_matches_individual() {
+        let mut scalars: Vec<Scalar> = (1u64..=5).map(Scalar::from).collect();
+        let expected: Vec<Scalar> = scalars.iter().map(|s| s.invert().unwrap()).collect();
+        assert!(bool::from(Scalar::batch_invert(&mut scalars).is_some()));
+        assert_eq!(scalars, expected);
+    }
+
+    /// A zero anywhere in the batch should fail the whole call and leave
+    /// every input exactly as it was.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert_rejects_zero_and_restores_inputs() {
+        let original = [Scalar::from(1u64), Scalar::ZERO, Scalar::from(3u64)];
+        let mut scalars = original;
+        let result = Scalar::batch_invert(&mut scalars);
+        assert!(bool::from(result.is_none()));
+        assert_eq!(scalars, original);
+    }
+
     /// Basic tests that sqrt works.
     #[test]
     fn sqrt() {
@@ -655,4 +1063,145 @@ mod tests {
             assert_eq!(sqrt.square(), scalar);
         }
     }
+
+    /// `reduce_wide` of a small value should be a no-op, and reducing the
+    /// curve order itself should land on zero.
+    #[test]
+    fn reduce_wide_known_answer() {
+        let mut small_bytes = [0u8; 96];
+        small_bytes[95] = 5;
+        let reduced = Scalar::reduce_wide(U768::from_be_bytes(small_bytes));
+        assert_eq!(reduced, small_scalar(5));
+
+        let mut order_bytes = [0u8; 96];
+        order_bytes[48..].copy_from_slice(&NistP384::ORDER.to_be_bytes());
+        let reduced_order = Scalar::reduce_wide(U768::from_be_bytes(order_bytes));
+        assert_eq!(reduced_order, Scalar::ZERO);
+
+        // `2^384` has a nonzero high half (`hi = 1, lo = 0`), so this
+        // exercises the `hi * r_384()` fold that the two cases above (both
+        // `hi = 0`) never touch. Expected value is `R = 2^384 mod n`,
+        // computed independently of `r_384()`'s own hardcoded constant.
+        let mut two_pow_384_bytes = [0u8; 96];
+        two_pow_384_bytes[47] = 1;
+        let reduced_two_pow_384 = Scalar::reduce_wide(U768::from_be_bytes(two_pow_384_bytes));
+        let expected_r = Scalar::from_repr(FieldBytes::from([
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x9c, 0xb2, 0x7e,
+            0x0b, 0xc8, 0xd2, 0x20, 0xa7, 0xe5, 0xf2, 0x4d, 0xb7, 0x4f, 0x58, 0x85, 0x13, 0x13,
+            0xe6, 0x95, 0x33, 0x3a, 0xd6, 0x8d,
+        ]))
+        .unwrap();
+        assert_eq!(reduced_two_pow_384, expected_r);
+    }
+
+    /// The scalar whose integer value is `n`.
+    fn small_scalar(n: u64) -> Scalar {
+        let mut bytes = [0u8; 48];
+        bytes[..8].copy_from_slice(&n.to_le_bytes());
+        Scalar::from_le_bytes(&bytes).unwrap()
+    }
+
+    /// Reconstruct a scalar from its NAF digits and check it round-trips,
+    /// including for values whose top bits are all set (the case the
+    /// `non_adjacent_form` loop bound has to cover).
+    fn check_naf_round_trip(scalar: Scalar, w: usize) {
+        let naf = scalar.non_adjacent_form(w);
+        let mut reconstructed = Scalar::ZERO;
+        let two = small_scalar(2);
+        for &digit in naf.iter().rev() {
+            reconstructed *= two;
+            let digit = digit as i16;
+            if digit >= 0 {
+                reconstructed += small_scalar(digit as u64);
+            } else {
+                reconstructed -= small_scalar((-digit) as u64);
+            }
+        }
+        assert_eq!(reconstructed, scalar, "NAF round-trip failed for w = {w}");
+    }
+
+    #[test]
+    fn non_adjacent_form_round_trips() {
+        let order_minus_one =
+            Scalar::from_le_bytes(&NistP384::ORDER.wrapping_sub(&U384::ONE).to_le_bytes()).unwrap();
+        for &w in &[2usize, 4, 5, 8] {
+            check_naf_round_trip(Scalar::from(42u64), w);
+            check_naf_round_trip(order_minus_one, w);
+        }
+    }
+
+    /// Reconstructing a scalar from its balanced base-`2^w` digits should
+    /// give back the original value.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_radix_2w_round_trips() {
+        let scalar = Scalar::from(0xdead_beef_u64);
+        let radix = small_scalar(1 << 5);
+        let mut reconstructed = Scalar::ZERO;
+        for &digit in scalar.to_radix_2w(5).iter().rev() {
+            reconstructed *= radix;
+            let digit = digit as i16;
+            if digit >= 0 {
+                reconstructed += small_scalar(digit as u64);
+            } else {
+                reconstructed -= small_scalar((-digit) as u64);
+            }
+        }
+        assert_eq!(reconstructed, scalar);
+    }
+
+    /// Regression test for the edge case where the reduced 768-bit value
+    /// lands exactly on `n - 2`: the final `+ 1` step must land on `n - 1`,
+    /// not wrap around to zero.
+    #[test]
+    fn nonzero_reduction_never_zero() {
+        // `n - 2` is already below the modulus `n - 1`, so it reduces to
+        // itself, the largest possible remainder and the tightest case for
+        // the `+ 1` step below.
+        let mut bytes = [0u8; 96];
+        bytes[48..].copy_from_slice(
+            &NistP384::ORDER
+                .wrapping_sub(&U384::ONE)
+                .wrapping_sub(&U384::ONE)
+                .to_be_bytes(),
+        );
+        let w = U768::from_be_bytes(bytes);
+        let scalar = <Scalar as elliptic_curve::ops::ReduceNonZero<U768>>::from_uint_reduced_nonzero(w);
+        assert!(bool::from(!scalar.is_zero()));
+    }
+
+    /// A scalar should round-trip through serde untouched.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let scalar = Scalar::from(0x0123_4567_89ab_cdefu64);
+        let json = serde_json::to_string(&scalar).unwrap();
+        let deserialized: Scalar = serde_json::from_str(&json).unwrap();
+        assert_eq!(scalar, deserialized);
+    }
+
+    /// `ct_gt` on non-Montgomery limbs should agree with the ordinary
+    /// integer ordering of small scalars built from `u64`s.
+    #[test]
+    fn ct_gt_orders_small_scalars() {
+        let small = Scalar::from(2u64).to_non_mont();
+        let big = Scalar::from(7u64).to_non_mont();
+        assert!(bool::from(big.ct_gt(&small)));
+        assert!(!bool::from(small.ct_gt(&big)));
+        assert!(!bool::from(small.ct_gt(&small)));
+    }
+
+    /// `is_high` must compare the scalar's integer value, not its
+    /// Montgomery-domain limbs — a small scalar is never high, and
+    /// `n - 1` always is.
+    #[test]
+    fn is_high_compares_integer_value() {
+        let small = Scalar::from(2u64);
+        assert!(!bool::from(small.is_high()));
+
+        let order_minus_one =
+            Scalar::from_le_bytes(&NistP384::ORDER.wrapping_sub(&U384::ONE).to_le_bytes()).unwrap();
+        assert!(bool::from(order_minus_one.is_high()));
+    }
 }